@@ -2,31 +2,42 @@
     Js -> Wasm/Rust Glue
 */
 
-use std::sync::Mutex;
+use std::sync::OnceLock;
 
-use macroquad::prelude::info;
+use futures::channel::mpsc::{self, UnboundedReceiver, UnboundedSender};
 use sapp_jsutils::JsObject;
 
-static MESSAGES_FROM_JS: Mutex<Vec<MessageFromJs>> = Mutex::new(Vec::new());
-pub fn push_new_js_message(message: MessageFromJs) {
-    let mut l = MESSAGES_FROM_JS.lock().unwrap();
-    l.push(message);
+static JS_MESSAGE_SENDER: OnceLock<UnboundedSender<MessageFromJs>> = OnceLock::new();
+
+// Creates the JS -> Rust message channel and hands back the receiving end.
+// Must be called exactly once, before any JS callback can fire.
+pub fn js_messages() -> UnboundedReceiver<MessageFromJs> {
+    let (tx, rx) = mpsc::unbounded();
+    JS_MESSAGE_SENDER
+        .set(tx)
+        .unwrap_or_else(|_| panic!("js_messages() must only be called once"));
+    rx
+}
+
+// Lock-free: callbacks run on JS's turn and must never block waiting on Rust.
+fn push_new_js_message(message: MessageFromJs) {
+    if let Some(tx) = JS_MESSAGE_SENDER.get() {
+        let _ = tx.unbounded_send(message);
+    }
 }
 
-pub fn read_new_js_messages() -> Vec<MessageFromJs> {
-    let mut l = MESSAGES_FROM_JS.lock().unwrap();
-    if l.len() > 0 {}
-    let mut messages = Vec::new();
-    std::mem::swap(&mut *l, &mut messages);
-    if messages.len() > 0 {}
-    messages
+// One step of a multi-step bridging flow, as reported by the JS side.
+pub struct BridgingProgress {
+    pub step: u32,
+    pub total_steps: u32,
+    pub message: String,
 }
 
 pub enum MessageFromJs {
     NexusInitializationFailed(String),
     NexusInitializationSucceeded,
     BridgingFailed(String),
-    BridgingStep(String),
+    BridgingProgress(BridgingProgress),
     BridgingSucceed,
 }
 
@@ -52,11 +63,15 @@ unsafe extern "C" fn bridging_failed(js_obj: JsObject) {
 }
 
 #[unsafe(no_mangle)]
-unsafe extern "C" fn bridging_step(js_obj: JsObject) {
+unsafe extern "C" fn bridging_progress(step: u32, total_steps: u32, js_obj: JsObject) {
     let mut message = String::new();
 
     js_obj.to_string(&mut message);
-    push_new_js_message(MessageFromJs::BridgingStep(message))
+    push_new_js_message(MessageFromJs::BridgingProgress(BridgingProgress {
+        step,
+        total_steps,
+        message,
+    }))
 }
 
 #[unsafe(no_mangle)]
@@ -4,7 +4,7 @@ mod utils;
 
 use crate::{
     screens::{BridgeScreen, ErrorScreen, InitializeScreen, MainScreen, WaitingForNexusInitScreen},
-    utils::FrameContext,
+    utils::{FrameContext, HitboxId, theme::Theme},
 };
 use macroquad::{text::load_ttf_font, window::Conf};
 
@@ -14,19 +14,27 @@ fn window_conf() -> Conf {
 
 #[macroquad::main(window_conf)]
 async fn main() {
-    let font = load_ttf_font("./media/Roboto-Medium.ttf").await.unwrap();
+    // A missing/renamed weight falls back to the system default font rather
+    // than crashing the example; every draw path already tolerates `font: None`.
+    let mut theme = Theme::light();
+    theme.normal.font = load_ttf_font("./media/Roboto-Medium.ttf").await.ok();
+    theme.demibold.font = load_ttf_font("./media/Roboto-SemiBold.ttf").await.ok();
+    theme.bold.font = load_ttf_font("./media/Roboto-Bold.ttf").await.ok();
+    theme.mono.font = load_ttf_font("./media/RobotoMono-Regular.ttf").await.ok();
 
     let mut ctx = FrameContext::default();
-    ctx.text_font = Some(font);
+    ctx.theme = theme;
+    let mut js_messages = glue::rust::js_messages();
+
     InitializeScreen::run(&mut ctx).await;
-    let res = WaitingForNexusInitScreen::run(&mut ctx).await;
+    let res = WaitingForNexusInitScreen::run(&mut ctx, &mut js_messages).await;
     if let Err(error) = res {
         ErrorScreen::run(&mut ctx, error).await;
     }
 
     loop {
-        MainScreen::run(&mut ctx).await;
-        let res = BridgeScreen::run(&mut ctx).await;
+        let amount = MainScreen::run(&mut ctx).await;
+        let res = BridgeScreen::run(&mut ctx, &mut js_messages, amount).await;
         if let Err(error) = res {
             ErrorScreen::run(&mut ctx, error).await;
         }
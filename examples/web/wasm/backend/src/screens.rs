@@ -1,37 +1,48 @@
+use futures::channel::mpsc::UnboundedReceiver;
 use macroquad::{
-    color::WHITE,
     math::vec2,
     window::{clear_background, next_frame},
 };
 
 use crate::{
     FrameContext,
-    glue::{
-        self,
-        rust::{MessageFromJs, read_new_js_messages},
-    },
+    glue::{self, rust::MessageFromJs},
     utils::{
-        elements::{CustomButton, CustomText},
-        layout::{ElementLayout, vertically_center_elements},
+        elements::{
+            Card, CustomButton, CustomText, Grid, PhantomDiv, ProgressBar, Spinner, TextInput,
+            TextInputMode, Wrap,
+        },
+        layout::{ElementLayout, horizontally_center_elements},
+        theme::TextRole,
     },
 };
 
+// Id of the amount input on `MainScreen`; stable across frames so focus survives redraws.
+const AMOUNT_INPUT_ID: u64 = 1;
+
+// Tokens `MainScreen` advertises as bridgeable, flowed into a `Wrap`.
+const SUPPORTED_TOKENS: [&str; 4] = ["USDC", "USDT", "DAI", "ETH"];
+
 pub struct InitializeScreen;
 impl InitializeScreen {
     pub async fn run(ctx: &mut FrameContext) {
         loop {
-            clear_background(WHITE);
+            clear_background(ctx.theme.background);
             ctx.update();
 
             // Draw button and Text :)
             let text = "Initialize Nexus Button. Click Me :)";
-            let mut btn = CustomButton::new(text).dim(vec2(600.0, 100.0));
-            btn.style.font_size = Some(32);
-            btn.style.font = ctx.text_font.clone();
+            let mut btn = CustomButton::new(text)
+                .dim(vec2(600.0, 100.0))
+                .role(ctx, TextRole::Demibold);
             btn.horizontally_center(0.0, ctx.screen_dim.x);
             btn.vertically_center(0.0, ctx.screen_dim.y);
 
-            let clicked = btn.draw(ctx);
+            // after_layout phase: register hitboxes in the order elements will be painted
+            let btn_hitbox = btn.register_hitbox(ctx);
+
+            // paint phase
+            let clicked = btn.draw(ctx, btn_hitbox);
             if clicked {
                 return;
             }
@@ -42,21 +53,21 @@ impl InitializeScreen {
 
 pub struct WaitingForNexusInitScreen;
 impl WaitingForNexusInitScreen {
-    pub async fn run(ctx: &mut FrameContext) -> Result<(), String> {
+    pub async fn run(
+        ctx: &mut FrameContext,
+        js_messages: &mut UnboundedReceiver<MessageFromJs>,
+    ) -> Result<(), String> {
         // Call Initialize Nexus on JS side
         unsafe {
             glue::js::initialize_nexus();
         }
 
-        let mut rotation = 0f32;
-        let mut tick = 0;
         loop {
-            clear_background(WHITE);
+            clear_background(ctx.theme.background);
             ctx.update();
 
-            // Read JS messages
-            let js_messages = read_messages(&mut tick);
-            for message in js_messages {
+            // Drain every message waiting on the channel this frame.
+            while let Ok(Some(message)) = js_messages.try_next() {
                 match message {
                     MessageFromJs::NexusInitializationFailed(reason) => return Err(reason),
                     MessageFromJs::NexusInitializationSucceeded => return Ok(()),
@@ -64,15 +75,25 @@ impl WaitingForNexusInitScreen {
                 }
             }
 
-            // Draw button and Text :)
-            let mut p = CustomText::new("Waiting for Nexus. :spinner:").font_size(32);
-            p.font = ctx.text_font.clone();
-            p.rotation = rotation;
-            p.horizontally_center(0.0, ctx.screen_dim.x);
-            p.vertically_center(0.0, ctx.screen_dim.y);
+            // Draw spinner and Text :)
+            let mut spinner = Spinner::new()
+                .radius(16.0)
+                .thickness(4.0)
+                .color(ctx.theme.normal.color);
+            let mut p = CustomText::new("Waiting for Nexus").role(ctx, TextRole::Normal);
+
+            horizontally_center_elements(0.0, ctx.screen_dim.x, 16.0, &mut [&mut spinner, &mut p]);
+            PhantomDiv {
+                elements: &mut [&mut spinner, &mut p],
+            }
+            .vertically_center(0.0, ctx.screen_dim.y);
+            // `PhantomDiv` has no text ascent of its own, so its `vertically_center`
+            // doesn't compensate for `p`'s baseline the way `CustomText`'s does.
+            // Apply that compensation manually, same as `BridgeScreen`.
+            p.pos.y += p.text_height().unwrap_or(0.0);
 
+            spinner.draw();
             p.draw();
-            rotation += 0.001;
 
             next_frame().await
         }
@@ -81,42 +102,89 @@ impl WaitingForNexusInitScreen {
 
 pub struct MainScreen;
 impl MainScreen {
-    pub async fn run(ctx: &mut FrameContext) {
+    // Returns the bridge amount the user typed into the amount input, or 0.01
+    // when the input does not parse as a number (e.g. it is empty).
+    pub async fn run(ctx: &mut FrameContext) -> f64 {
+        let mut amount_input = TextInput::new(AMOUNT_INPUT_ID)
+            .dim(vec2(200.0, 60.0))
+            .mode(TextInputMode::Decimal)
+            .role(ctx, TextRole::Mono);
+        amount_input.buffer = String::from("0.01");
+
         loop {
-            clear_background(WHITE);
+            clear_background(ctx.theme.background);
             ctx.update();
 
-            // Draw button and Text :)
+            // Draw button and Text :) - address/balance use the mono role so they render in fixed width
             let text = "Current Account Address: 0x198866cD002F9e5E2b49DE96d68EaE9d32aD0000";
-            let mut p1 = CustomText::new(text).font_size(32);
-            p1.font = ctx.text_font.clone();
+            let mut p1 = CustomText::new(text).role(ctx, TextRole::Mono);
 
             let text = "Current Account Unified Balance: 100 USDC";
-            let mut p2 = CustomText::new(text).font_size(32);
-            p2.font = ctx.text_font.clone();
+            let mut p2 = CustomText::new(text).role(ctx, TextRole::Mono);
+
+            let text = "Bridge and Transfer (USDC) to that address";
+            let mut p3 = CustomText::new(text).role(ctx, TextRole::Normal);
+
+            // Supported tokens, flowed left to right and wrapping once they run out
+            // of room, instead of one more hardcoded stacked line.
+            let mut tokens: Vec<CustomText> = SUPPORTED_TOKENS
+                .iter()
+                .map(|token| CustomText::new(*token).role(ctx, TextRole::Mono))
+                .collect();
+            let mut token_elements: Vec<&mut dyn ElementLayout> = tokens
+                .iter_mut()
+                .map(|token| token as &mut dyn ElementLayout)
+                .collect();
+            let mut token_list = Wrap::new(&mut token_elements).spacing(12.0).line_spacing(8.0);
+            token_list.layout(vec2(0.0, 0.0), 260.0);
 
-            let text = "Bridge and Transfer 0.01 USDC to that address";
-            let mut btn = CustomButton::new(text).dim(vec2(500.0, 100.0));
+            let mut btn = CustomButton::new("Bridge")
+                .dim(vec2(200.0, 60.0))
+                .role(ctx, TextRole::Demibold);
             btn.style.font_size = Some(22);
-            btn.style.font = ctx.text_font.clone();
             btn.style.thickness = Some(4.0);
 
-            p1.horizontally_center(0.0, ctx.screen_dim.x);
-            p2.horizontally_center(0.0, ctx.screen_dim.x);
-            btn.horizontally_center(0.0, ctx.screen_dim.x);
+            // A single action today, arranged through a `Grid` (rather than a
+            // one-off centering call) so a second action slots in later
+            // without new layout code.
+            let mut action_elements: [&mut dyn ElementLayout; 1] = [&mut btn];
+            let mut actions = Grid::new(&mut action_elements, 1);
+            actions.layout(vec2(0.0, 0.0));
+
+            // See `Card`'s doc comment for why body/footer are locals, scoped here.
+            {
+                let mut body: [&mut dyn ElementLayout; 5] =
+                    [&mut p1, &mut p2, &mut p3, &mut amount_input, &mut token_list];
+                let mut footer: [&mut dyn ElementLayout; 1] = [&mut actions];
+
+                let mut card = Card::new(
+                    CustomText::new("Account").role(ctx, TextRole::Demibold),
+                    &mut body,
+                    &mut footer,
+                )
+                .border_color(ctx.theme.normal.color);
+
+                card.horizontally_center(0.0, ctx.screen_dim.x);
+                card.vertically_center(0.0, ctx.screen_dim.y);
+                card.arrange();
+                card.draw();
+            }
 
-            vertically_center_elements(
-                0.0,
-                ctx.screen_dim.y,
-                25.0,
-                &mut [&mut p1, &mut p2, &mut btn],
-            );
+            // after_layout phase: register hitboxes in the order elements will be painted
+            let amount_input_hitbox = amount_input.register_hitbox(ctx);
+            let btn_hitbox = btn.register_hitbox(ctx);
 
+            // paint phase
             p1.draw();
             p2.draw();
-            let clicked = btn.draw(&ctx);
+            p3.draw();
+            for token in tokens.iter() {
+                token.draw();
+            }
+            amount_input.draw(ctx, amount_input_hitbox);
+            let clicked = btn.draw(ctx, btn_hitbox);
             if clicked {
-                return;
+                return amount_input.value().unwrap_or(0.01);
             }
 
             next_frame().await
@@ -126,24 +194,37 @@ impl MainScreen {
 
 pub struct BridgeScreen;
 impl BridgeScreen {
-    pub async fn run(ctx: &mut FrameContext) -> Result<(), String> {
+    pub async fn run(
+        ctx: &mut FrameContext,
+        js_messages: &mut UnboundedReceiver<MessageFromJs>,
+        amount: f64,
+    ) -> Result<(), String> {
         // Call Initiate Bridge And Transfer on JS side
         unsafe {
-            glue::js::initiate_bridge_and_transfer();
+            glue::js::initiate_bridge_and_transfer(amount);
         }
 
-        let mut tick = 0;
         let mut text = String::from("Waiting...");
+        let mut progress = 0.0f32;
         loop {
-            clear_background(WHITE);
+            clear_background(ctx.theme.background);
             ctx.update();
 
-            // Read JS messages
-            let js_messages = read_messages(&mut tick);
-            for message in js_messages {
+            // Drain every message waiting on the channel this frame.
+            while let Ok(Some(message)) = js_messages.try_next() {
                 match message {
-                    MessageFromJs::BridgingStep(reason) => {
-                        text = reason;
+                    MessageFromJs::BridgingProgress(bridging_progress) => {
+                        text = format!(
+                            "Step {}/{}: {}",
+                            bridging_progress.step,
+                            bridging_progress.total_steps,
+                            bridging_progress.message
+                        );
+                        progress = if bridging_progress.total_steps > 0 {
+                            bridging_progress.step as f32 / bridging_progress.total_steps as f32
+                        } else {
+                            0.0
+                        };
                     }
                     MessageFromJs::BridgingFailed(reason) => return Err(reason),
                     MessageFromJs::BridgingSucceed => return Ok(()),
@@ -151,13 +232,50 @@ impl BridgeScreen {
                 }
             }
 
-            // Draw button and Text :)
-            let mut p = CustomText::new(text.as_str()).font_size(32);
-            p.font = ctx.text_font.clone();
-            p.horizontally_center(0.0, ctx.screen_dim.x);
-            p.vertically_center(0.0, ctx.screen_dim.y);
+            // Draw spinner, text and a progress bar, inside a card with a
+            // hover-colored header so progress reads distinctly from the error
+            // screen below.
+            let mut spinner = Spinner::new()
+                .radius(16.0)
+                .thickness(4.0)
+                .color(ctx.theme.normal.color);
+            let mut p = CustomText::new(text.as_str()).role(ctx, TextRole::Normal);
+            p.pos = vec2(spinner.layout_dim().x + 16.0, p.text_height().unwrap_or(0.0));
+
+            let row_height = spinner.layout_dim().y.max(p.layout_dim().y);
+            let mut bar = ProgressBar::new(progress)
+                .dim(vec2(260.0, 12.0))
+                .color(ctx.theme.hover)
+                .track_color(ctx.theme.normal.color);
+            bar.pos = vec2(0.0, row_height + 12.0);
+
+            // See `Card`'s doc comment for why body/footer are locals.
+            {
+                let mut row = PhantomDiv {
+                    elements: &mut [&mut spinner, &mut p],
+                };
+                let mut group = PhantomDiv {
+                    elements: &mut [&mut row, &mut bar],
+                };
+                let mut body: [&mut dyn ElementLayout; 1] = [&mut group];
+                let mut footer: [&mut dyn ElementLayout; 0] = [];
+                let mut card = Card::new(
+                    CustomText::new("Bridging").role(ctx, TextRole::Demibold),
+                    &mut body,
+                    &mut footer,
+                )
+                .header_color(ctx.theme.hover)
+                .border_color(ctx.theme.normal.color);
+
+                card.horizontally_center(0.0, ctx.screen_dim.x);
+                card.vertically_center(0.0, ctx.screen_dim.y);
+                card.arrange();
+                card.draw();
+            }
 
+            spinner.draw();
             p.draw();
+            bar.draw();
 
             next_frame().await
         }
@@ -168,31 +286,39 @@ pub struct ErrorScreen;
 impl ErrorScreen {
     pub async fn run(ctx: &mut FrameContext, error: String) {
         loop {
-            clear_background(WHITE);
+            clear_background(ctx.theme.background);
             ctx.update();
 
-            // Draw button and Text :)
+            // Draw the error inside a card with a danger-colored header.
             let mut font_size = 32;
             if error.len() > 50 {
                 font_size = 20;
             }
-            let mut p = CustomText::new(error.as_str()).font_size(font_size);
-            p.font = ctx.text_font.clone();
-            p.horizontally_center(0.0, ctx.screen_dim.x);
-            p.vertically_center(0.0, ctx.screen_dim.y);
+            let mut p = CustomText::new(error.as_str())
+                .role(ctx, TextRole::Bold)
+                .font_size(font_size);
+
+            // See `Card`'s doc comment for why body/footer are locals, scoped here.
+            {
+                let mut body: [&mut dyn ElementLayout; 1] = [&mut p];
+                let mut footer: [&mut dyn ElementLayout; 0] = [];
+                let mut card = Card::new(
+                    CustomText::new("Error").role(ctx, TextRole::Demibold),
+                    &mut body,
+                    &mut footer,
+                )
+                .header_color(ctx.theme.danger)
+                .border_color(ctx.theme.normal.color);
+
+                card.horizontally_center(0.0, ctx.screen_dim.x);
+                card.vertically_center(0.0, ctx.screen_dim.y);
+                card.arrange();
+                card.draw();
+            }
 
             p.draw();
+
             next_frame().await
         }
     }
 }
-
-// Read js messages every 200 ticks for performances purposes
-pub fn read_messages(tick: &mut u32) -> Vec<MessageFromJs> {
-    if *tick < 200 {
-        *tick += 1;
-        return Vec::new();
-    }
-    *tick = 0;
-    return read_new_js_messages();
-}
@@ -1,12 +1,20 @@
 use macroquad::{
-    color::{BLACK, Color, DARKGRAY, GREEN},
+    color::{BLACK, Color},
+    input::{KeyCode, get_char_pressed, is_key_pressed},
     math::{Vec2, vec2},
     prelude::info,
-    shapes::{draw_rectangle, draw_rectangle_lines},
+    shapes::{draw_line, draw_rectangle, draw_rectangle_lines},
     text::{Font, TextDimensions, TextParams, draw_text_ex},
+    time::get_time,
 };
 
-use crate::{FrameContext, utils::layout::ElementLayout};
+use crate::{
+    FrameContext, HitboxId,
+    utils::{
+        layout::{ElementLayout, SpaceBetweenElements, calculate_margin},
+        theme::TextRole,
+    },
+};
 
 #[derive(Default)]
 pub struct CustomText {
@@ -14,6 +22,7 @@ pub struct CustomText {
     pub pos: Vec2,
     pub font: Option<Font>,
     pub font_size: Option<u16>,
+    pub color: Option<Color>,
     pub left_margin: Option<f32>,
     pub rotation: f32,
 }
@@ -41,6 +50,16 @@ impl CustomText {
         self
     }
 
+    // Resolves font, size and color from the theme's named role, so screens
+    // don't pass fonts around or hardcode sizes/colors by hand.
+    pub fn role(mut self, ctx: &FrameContext, role: TextRole) -> Self {
+        let font_role = ctx.theme.role(role);
+        self.font = font_role.font.clone();
+        self.font_size = Some(font_role.size);
+        self.color = Some(font_role.color);
+        self
+    }
+
     pub fn draw(&self) {
         // Draw at the position
         let pos = self.pos + vec2(self.left_margin.unwrap_or_default(), 0.0);
@@ -48,7 +67,7 @@ impl CustomText {
 
         let mut text_params = TextParams::default();
         text_params.font = self.font.as_ref();
-        text_params.color = BLACK;
+        text_params.color = self.color.unwrap_or(BLACK);
         text_params.font_size = font_size;
         text_params.rotation = self.rotation;
         draw_text_ex(&self.text, pos.x, pos.y, text_params);
@@ -88,6 +107,7 @@ pub struct ButtonStyle {
     pub bg_color: Option<Color>,
     pub font: Option<Font>,
     pub font_size: Option<u16>,
+    pub color: Option<Color>,
     pub disabled: bool,
     pub thickness: Option<f32>,
 }
@@ -132,23 +152,33 @@ impl CustomButton {
         self
     }
 
-    // True if the button was clicked.
-    pub fn draw(&self, ctx: &FrameContext) -> bool {
+    // See `CustomText::role`.
+    pub fn role(mut self, ctx: &FrameContext, role: TextRole) -> Self {
+        let font_role = ctx.theme.role(role);
+        self.style.font = font_role.font.clone();
+        self.style.font_size = Some(font_role.size);
+        self.style.color = Some(font_role.color);
+        self
+    }
+
+    // True if the button was clicked. `hitbox` must have been registered via
+    // `register_hitbox` for this button, in paint order, earlier this frame.
+    pub fn draw(&self, ctx: &FrameContext, hitbox: HitboxId) -> bool {
         // Draw at the position
         let pos = self.pos;
         let dim = self.actual_dim();
 
-        let mouse_intersect = self.intersect_point(ctx.mouse_pos);
+        let hovered = ctx.is_topmost_hovered(hitbox);
 
         // Draw Button Background
-        let mut color = BLACK;
+        let mut color = self.style.color.unwrap_or(BLACK);
         let mut thickness = self.style.thickness.unwrap_or(2.0);
-        if mouse_intersect {
-            color = GREEN;
+        if hovered {
+            color = ctx.theme.hover;
             thickness = 6.0;
         }
         if self.style.disabled {
-            draw_rectangle(pos.x, pos.y, dim.x, dim.y, DARKGRAY);
+            draw_rectangle(pos.x, pos.y, dim.x, dim.y, ctx.theme.disabled);
         } else {
             draw_rectangle_lines(pos.x, pos.y, dim.x, dim.y, thickness, color);
         }
@@ -156,12 +186,13 @@ impl CustomButton {
         let mut text =
             CustomText::new(self.text.clone()).font_size(self.style.font_size.unwrap_or(16));
         text.font = self.style.font.clone();
+        text.color = self.style.color;
         text.vertically_center(pos.y, dim.y);
         text.horizontally_center(pos.x, dim.x);
 
         text.draw();
 
-        mouse_intersect && ctx.let_mouse_button_released && !self.style.disabled
+        hovered && ctx.let_mouse_button_released && !self.style.disabled
     }
 
     fn actual_dim(&self) -> Vec2 {
@@ -189,41 +220,698 @@ pub struct PhantomDiv<'a> {
 
 impl<'a> ElementLayout for PhantomDiv<'a> {
     fn layout_dim(&self) -> Vec2 {
-        // Find top left and bottom right
-        let mut top_left = vec2(f32::MAX, f32::MAX);
-        let mut bottom_right = vec2(f32::MIN, f32::MIN);
+        let (top_left, bottom_right) = bounding_box(self.elements);
+        assert!(bottom_right.x >= top_left.x);
+        assert!(bottom_right.y >= top_left.y);
 
-        for e in self.elements.iter() {
-            let pos = e.layout_pos();
-            top_left.x = top_left.x.min(pos.x);
-            top_left.y = top_left.y.min(pos.y);
+        bottom_right - top_left
+    }
 
-            let dim = pos + e.layout_dim();
-            bottom_right.x = bottom_right.x.max(dim.x);
-            bottom_right.y = bottom_right.y.max(dim.y);
+    fn layout_pos(&self) -> Vec2 {
+        bounding_box(self.elements).0
+    }
+
+    fn layout_set_pos(&mut self, value: Vec2) {
+        let diff = value - self.layout_pos();
+        for e in self.elements.iter_mut() {
+            let pos = e.layout_pos() + diff;
+            e.layout_set_pos(pos);
         }
-        assert!(bottom_right.x >= top_left.x);
-        assert!(bottom_right.y >= top_left.y);
+    }
+
+    fn text_height(&self) -> Option<f32> {
+        topmost_text_height(self.elements)
+    }
+}
+
+// Bounding box (top left, bottom right) of a set of already-positioned elements.
+fn bounding_box(elements: &[&mut dyn ElementLayout]) -> (Vec2, Vec2) {
+    let mut top_left = vec2(f32::MAX, f32::MAX);
+    let mut bottom_right = vec2(f32::MIN, f32::MIN);
+
+    for e in elements.iter() {
+        let pos = e.layout_pos();
+        top_left.x = top_left.x.min(pos.x);
+        top_left.y = top_left.y.min(pos.y);
+
+        let dim = pos + e.layout_dim();
+        bottom_right.x = bottom_right.x.max(dim.x);
+        bottom_right.y = bottom_right.y.max(dim.y);
+    }
+
+    (top_left, bottom_right)
+}
+
+// The ascent baked into whichever child sits at the container's reported
+// top (e.g. a `CustomText` whose `pos` is its baseline, not its visual top).
+// Lets a container forward that child's `text_height()` as its own, so a
+// caller repositioning the whole container (like `Card::arrange`) can
+// compensate for the offset the same way it already does for a bare text
+// element, instead of the container's true top drifting by that ascent.
+fn topmost_text_height(elements: &[&mut dyn ElementLayout]) -> Option<f32> {
+    let top = bounding_box(elements).0.y;
+    elements
+        .iter()
+        .find(|e| e.layout_pos().y == top)
+        .and_then(|e| e.text_height())
+}
+
+pub struct Grid<'a> {
+    pub elements: &'a mut [&'a mut dyn ElementLayout],
+    pub columns: usize,
+    pub column_spacing: SpaceBetweenElements,
+    pub row_spacing: SpaceBetweenElements,
+}
+
+impl<'a> Grid<'a> {
+    pub fn new(elements: &'a mut [&'a mut dyn ElementLayout], columns: usize) -> Self {
+        Self {
+            elements,
+            columns: columns.max(1),
+            column_spacing: SpaceBetweenElements::Value(0.0),
+            row_spacing: SpaceBetweenElements::Value(0.0),
+        }
+    }
+
+    pub fn column_spacing(mut self, value: impl Into<SpaceBetweenElements>) -> Self {
+        self.column_spacing = value.into();
+        self
+    }
+
+    pub fn row_spacing(mut self, value: impl Into<SpaceBetweenElements>) -> Self {
+        self.row_spacing = value.into();
+        self
+    }
 
+    // Arranges the children into `self.columns` fixed-size columns, each
+    // child centered within its cell, starting at `pos`.
+    pub fn layout(&mut self, pos: Vec2) {
+        let columns = self.columns;
+        let col_gap = self.column_spacing.single_value();
+        let row_gap = self.row_spacing.single_value();
+
+        let cell_width = self
+            .elements
+            .iter()
+            .fold(0.0f32, |acc, e| acc.max(e.layout_dim().x));
+        let cell_height = self
+            .elements
+            .iter()
+            .fold(0.0f32, |acc, e| acc.max(e.layout_dim().y));
+
+        for (i, e) in self.elements.iter_mut().enumerate() {
+            let col = i % columns;
+            let row = i / columns;
+
+            let cell_pos = pos
+                + vec2(
+                    col as f32 * (cell_width + col_gap),
+                    row as f32 * (cell_height + row_gap),
+                );
+
+            let dim = e.layout_dim();
+            let margin_x = calculate_margin(dim.x, cell_width) / 2.0;
+            let margin_y = calculate_margin(dim.y, cell_height) / 2.0;
+            let text_height = e.text_height().unwrap_or(0.0);
+
+            e.layout_set_pos(cell_pos + vec2(margin_x, margin_y + text_height));
+        }
+    }
+}
+
+impl<'a> ElementLayout for Grid<'a> {
+    fn layout_dim(&self) -> Vec2 {
+        let (top_left, bottom_right) = bounding_box(self.elements);
         bottom_right - top_left
     }
 
     fn layout_pos(&self) -> Vec2 {
-        let mut top_left = vec2(f32::MAX, f32::MAX);
-        for e in self.elements.iter() {
-            let pos = e.layout_pos();
-            top_left.x = top_left.x.min(pos.x);
-            top_left.y = top_left.y.min(pos.y);
+        bounding_box(self.elements).0
+    }
+
+    fn layout_set_pos(&mut self, value: Vec2) {
+        let diff = value - self.layout_pos();
+        for e in self.elements.iter_mut() {
+            let pos = e.layout_pos() + diff;
+            e.layout_set_pos(pos);
         }
-        top_left
+    }
+
+    fn text_height(&self) -> Option<f32> {
+        topmost_text_height(self.elements)
+    }
+}
+
+pub struct Wrap<'a> {
+    pub elements: &'a mut [&'a mut dyn ElementLayout],
+    pub spacing: SpaceBetweenElements,
+    pub line_spacing: SpaceBetweenElements,
+}
+
+impl<'a> Wrap<'a> {
+    pub fn new(elements: &'a mut [&'a mut dyn ElementLayout]) -> Self {
+        Self {
+            elements,
+            spacing: SpaceBetweenElements::Value(0.0),
+            line_spacing: SpaceBetweenElements::Value(0.0),
+        }
+    }
+
+    pub fn spacing(mut self, value: impl Into<SpaceBetweenElements>) -> Self {
+        self.spacing = value.into();
+        self
+    }
+
+    pub fn line_spacing(mut self, value: impl Into<SpaceBetweenElements>) -> Self {
+        self.line_spacing = value.into();
+        self
+    }
+
+    // Flows children left to right starting at `pos`, breaking to a new line
+    // whenever the next child would exceed `max_width`.
+    pub fn layout(&mut self, pos: Vec2, max_width: f32) {
+        let gap = self.spacing.single_value();
+        let line_gap = self.line_spacing.single_value();
+
+        let mut cursor = pos;
+        let mut line_height = 0.0f32;
+
+        for e in self.elements.iter_mut() {
+            let dim = e.layout_dim();
+            if cursor.x > pos.x && cursor.x + dim.x > pos.x + max_width {
+                cursor.x = pos.x;
+                cursor.y += line_height + line_gap;
+                line_height = 0.0;
+            }
+
+            let text_height = e.text_height().unwrap_or(0.0);
+            e.layout_set_pos(cursor + vec2(0.0, text_height));
+
+            cursor.x += dim.x + gap;
+            line_height = line_height.max(dim.y);
+        }
+    }
+}
+
+impl<'a> ElementLayout for Wrap<'a> {
+    fn layout_dim(&self) -> Vec2 {
+        let (top_left, bottom_right) = bounding_box(self.elements);
+        bottom_right - top_left
+    }
+
+    fn layout_pos(&self) -> Vec2 {
+        bounding_box(self.elements).0
     }
 
     fn layout_set_pos(&mut self, value: Vec2) {
-        let current_pos = self.layout_pos();
-        let diff = value - current_pos;
+        let diff = value - self.layout_pos();
         for e in self.elements.iter_mut() {
             let pos = e.layout_pos() + diff;
             e.layout_set_pos(pos);
         }
     }
+
+    fn text_height(&self) -> Option<f32> {
+        topmost_text_height(self.elements)
+    }
+}
+
+#[derive(Default)]
+pub struct CardStyle {
+    pub bg_color: Option<Color>,
+    pub border_color: Option<Color>,
+    pub header_color: Option<Color>,
+    pub padding: Option<f32>,
+    pub thickness: Option<f32>,
+}
+
+// Spacing between stacked body/footer elements, and between sections.
+const CARD_ELEMENT_SPACING: f32 = 8.0;
+
+// Bind `body`/`footer` to locals before constructing a `Card`, rather than
+// passing array literals directly: the literals are temporaries dropped at
+// the end of the `let` statement, and `Card` would be left borrowing freed
+// data. Prefer scoping the card's construction/arrange/draw in its own
+// block too, so its borrow of the elements ends before they're needed again
+// for hitbox registration and drawing.
+pub struct Card<'a> {
+    pub pos: Vec2,
+    pub title: CustomText,
+    pub body: &'a mut [&'a mut dyn ElementLayout],
+    pub footer: &'a mut [&'a mut dyn ElementLayout],
+    pub style: CardStyle,
+}
+
+impl<'a> Card<'a> {
+    pub fn new(
+        title: CustomText,
+        body: &'a mut [&'a mut dyn ElementLayout],
+        footer: &'a mut [&'a mut dyn ElementLayout],
+    ) -> Self {
+        Self {
+            pos: Vec2::ZERO,
+            title,
+            body,
+            footer,
+            style: CardStyle::default(),
+        }
+    }
+
+    pub fn pos(mut self, value: Vec2) -> Self {
+        self.pos = value;
+        self
+    }
+
+    pub fn bg_color(mut self, value: Color) -> Self {
+        self.style.bg_color = Some(value);
+        self
+    }
+
+    pub fn header_color(mut self, value: Color) -> Self {
+        self.style.header_color = Some(value);
+        self
+    }
+
+    pub fn border_color(mut self, value: Color) -> Self {
+        self.style.border_color = Some(value);
+        self
+    }
+
+    pub fn padding(mut self, value: f32) -> Self {
+        self.style.padding = Some(value);
+        self
+    }
+
+    fn actual_padding(&self) -> f32 {
+        self.style.padding.unwrap_or(16.0)
+    }
+
+    fn content_width(&self) -> f32 {
+        let body_width = self
+            .body
+            .iter()
+            .fold(0.0f32, |acc, e| acc.max(e.layout_dim().x));
+        let footer_width = self
+            .footer
+            .iter()
+            .fold(0.0f32, |acc, e| acc.max(e.layout_dim().x));
+
+        body_width.max(footer_width).max(self.title.layout_dim().x)
+    }
+
+    fn header_height(&self) -> f32 {
+        self.title.layout_dim().y + self.actual_padding()
+    }
+
+    fn content_height(&self) -> f32 {
+        let padding = self.actual_padding();
+        let body_height: f32 = self
+            .body
+            .iter()
+            .map(|e| e.layout_dim().y + CARD_ELEMENT_SPACING)
+            .sum();
+        let footer_height: f32 = self
+            .footer
+            .iter()
+            .map(|e| e.layout_dim().y + CARD_ELEMENT_SPACING)
+            .sum();
+
+        self.header_height() + body_height + footer_height + padding
+    }
+
+    // Writes absolute positions into the title, body and footer elements
+    // based on `self.pos`. Call once `self.pos` is final (e.g. after
+    // centering the card itself), and before drawing the children.
+    pub fn arrange(&mut self) {
+        let padding = self.actual_padding();
+
+        let title_text_height = self.title.text_height().unwrap_or(0.0);
+        self.title
+            .layout_set_pos(self.pos + vec2(padding, padding + title_text_height));
+
+        let mut cursor_y = self.pos.y + self.header_height() + padding;
+        for e in self.body.iter_mut() {
+            let text_height = e.text_height().unwrap_or(0.0);
+            e.layout_set_pos(vec2(self.pos.x + padding, cursor_y + text_height));
+            cursor_y += e.layout_dim().y + CARD_ELEMENT_SPACING;
+        }
+
+        for e in self.footer.iter_mut() {
+            let text_height = e.text_height().unwrap_or(0.0);
+            e.layout_set_pos(vec2(self.pos.x + padding, cursor_y + text_height));
+            cursor_y += e.layout_dim().y + CARD_ELEMENT_SPACING;
+        }
+    }
+
+    // Paints the card's background, border and header strip. Body/footer
+    // children are drawn separately by the caller (after `arrange`), since
+    // they may need `FrameContext` (e.g. a footer `CustomButton`).
+    pub fn draw(&self) {
+        let dim = self.layout_dim();
+        let thickness = self.style.thickness.unwrap_or(2.0);
+
+        if let Some(bg_color) = self.style.bg_color {
+            draw_rectangle(self.pos.x, self.pos.y, dim.x, dim.y, bg_color);
+        }
+        if let Some(header_color) = self.style.header_color {
+            draw_rectangle(self.pos.x, self.pos.y, dim.x, self.header_height(), header_color);
+        }
+        if let Some(border_color) = self.style.border_color {
+            draw_rectangle_lines(self.pos.x, self.pos.y, dim.x, dim.y, thickness, border_color);
+        }
+
+        self.title.draw();
+    }
+}
+
+impl<'a> ElementLayout for Card<'a> {
+    fn layout_dim(&self) -> Vec2 {
+        vec2(
+            self.content_width() + self.actual_padding() * 2.0,
+            self.content_height(),
+        )
+    }
+
+    fn layout_pos(&self) -> Vec2 {
+        self.pos
+    }
+
+    fn layout_set_pos(&mut self, value: Vec2) {
+        self.pos = value
+    }
+}
+
+// Three-quarter turn arc so the animation always reads as motion, not a full ring.
+const SPINNER_ARC: f32 = std::f32::consts::TAU * 0.75;
+const SPINNER_SEGMENTS: u32 = 24;
+
+#[derive(Default)]
+pub struct Spinner {
+    pub pos: Vec2,
+    pub radius: Option<f32>,
+    pub thickness: Option<f32>,
+    pub color: Option<Color>,
+    pub revolutions_per_second: Option<f32>,
+}
+
+impl Spinner {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn pos(mut self, value: Vec2) -> Self {
+        self.pos = value;
+        self
+    }
+
+    pub fn radius(mut self, value: f32) -> Self {
+        self.radius = Some(value);
+        self
+    }
+
+    pub fn thickness(mut self, value: f32) -> Self {
+        self.thickness = Some(value);
+        self
+    }
+
+    pub fn color(mut self, value: Color) -> Self {
+        self.color = Some(value);
+        self
+    }
+
+    pub fn revolutions_per_second(mut self, value: f32) -> Self {
+        self.revolutions_per_second = Some(value);
+        self
+    }
+
+    // Draw an arc rotating from elapsed wall-clock time, so its speed does not
+    // depend on the frame rate.
+    pub fn draw(&self) {
+        let radius = self.actual_radius();
+        let center = self.pos + vec2(radius, radius);
+        let thickness = self.actual_thickness();
+        let color = self.actual_color();
+        let rotation =
+            get_time() as f32 * self.actual_revolutions_per_second() * std::f32::consts::TAU;
+
+        let mut prev: Option<Vec2> = None;
+        for i in 0..=SPINNER_SEGMENTS {
+            let angle = rotation + SPINNER_ARC * (i as f32 / SPINNER_SEGMENTS as f32);
+            let point = center + vec2(angle.cos(), angle.sin()) * radius;
+            if let Some(prev) = prev {
+                draw_line(prev.x, prev.y, point.x, point.y, thickness, color);
+            }
+            prev = Some(point);
+        }
+    }
+
+    fn actual_radius(&self) -> f32 {
+        self.radius.unwrap_or(20.0)
+    }
+
+    fn actual_thickness(&self) -> f32 {
+        self.thickness.unwrap_or(4.0)
+    }
+
+    fn actual_color(&self) -> Color {
+        self.color.unwrap_or(BLACK)
+    }
+
+    fn actual_revolutions_per_second(&self) -> f32 {
+        self.revolutions_per_second.unwrap_or(1.0)
+    }
+}
+
+impl ElementLayout for Spinner {
+    fn layout_dim(&self) -> Vec2 {
+        let diameter = self.actual_radius() * 2.0;
+        vec2(diameter, diameter)
+    }
+
+    fn layout_pos(&self) -> Vec2 {
+        self.pos
+    }
+
+    fn layout_set_pos(&mut self, value: Vec2) {
+        self.pos = value
+    }
+}
+
+// A determinate progress indicator, for flows with a known step count (as
+// opposed to `Spinner`, which just communicates "still working").
+#[derive(Default)]
+pub struct ProgressBar {
+    pub pos: Vec2,
+    pub dim: Option<Vec2>,
+    pub progress: f32,
+    pub color: Option<Color>,
+    pub track_color: Option<Color>,
+}
+
+impl ProgressBar {
+    // `progress` is clamped to [0.0, 1.0].
+    pub fn new(progress: f32) -> Self {
+        Self {
+            progress: progress.clamp(0.0, 1.0),
+            ..Default::default()
+        }
+    }
+
+    pub fn pos(mut self, value: Vec2) -> Self {
+        self.pos = value;
+        self
+    }
+
+    pub fn dim(mut self, value: Vec2) -> Self {
+        self.dim = Some(value);
+        self
+    }
+
+    pub fn color(mut self, value: Color) -> Self {
+        self.color = Some(value);
+        self
+    }
+
+    pub fn track_color(mut self, value: Color) -> Self {
+        self.track_color = Some(value);
+        self
+    }
+
+    pub fn draw(&self) {
+        let dim = self.actual_dim();
+
+        draw_rectangle_lines(
+            self.pos.x,
+            self.pos.y,
+            dim.x,
+            dim.y,
+            2.0,
+            self.track_color.unwrap_or(BLACK),
+        );
+        draw_rectangle(
+            self.pos.x,
+            self.pos.y,
+            dim.x * self.progress,
+            dim.y,
+            self.color.unwrap_or(BLACK),
+        );
+    }
+
+    fn actual_dim(&self) -> Vec2 {
+        self.dim.unwrap_or_else(|| vec2(200.0, 16.0))
+    }
+}
+
+impl ElementLayout for ProgressBar {
+    fn layout_dim(&self) -> Vec2 {
+        self.actual_dim()
+    }
+
+    fn layout_pos(&self) -> Vec2 {
+        self.pos
+    }
+
+    fn layout_set_pos(&mut self, value: Vec2) {
+        self.pos = value
+    }
+}
+
+#[derive(Default, Clone, Copy, PartialEq, Eq)]
+pub enum TextInputMode {
+    #[default]
+    Text,
+    Decimal,
+}
+
+#[derive(Default)]
+pub struct TextInput {
+    pub id: u64,
+    pub pos: Vec2,
+    pub dim: Option<Vec2>,
+    pub buffer: String,
+    pub mode: TextInputMode,
+    pub style: ButtonStyle,
+}
+
+impl TextInput {
+    // `id` must be unique among the inputs shown on a screen and stable
+    // across frames, so focus survives the element being rebuilt every frame.
+    pub fn new(id: u64) -> Self {
+        Self {
+            id,
+            ..Default::default()
+        }
+    }
+
+    pub fn pos(mut self, value: Vec2) -> Self {
+        self.pos = value;
+        self
+    }
+
+    pub fn dim(mut self, value: Vec2) -> Self {
+        self.dim = Some(value);
+        self
+    }
+
+    pub fn mode(mut self, value: TextInputMode) -> Self {
+        self.mode = value;
+        self
+    }
+
+    pub fn role(mut self, ctx: &FrameContext, role: TextRole) -> Self {
+        let font_role = ctx.theme.role(role);
+        self.style.font = font_role.font.clone();
+        self.style.font_size = Some(font_role.size);
+        self.style.color = Some(font_role.color);
+        self
+    }
+
+    pub fn value(&self) -> Option<f64> {
+        self.buffer.parse().ok()
+    }
+
+    fn is_focused(&self, ctx: &FrameContext) -> bool {
+        ctx.focused_input == Some(self.id)
+    }
+
+    fn accepts(&self, c: char) -> bool {
+        match self.mode {
+            TextInputMode::Text => !c.is_control(),
+            TextInputMode::Decimal => {
+                c.is_ascii_digit() || (c == '.' && !self.buffer.contains('.'))
+            }
+        }
+    }
+
+    // Gains focus on click (via the same topmost-hitbox test as `CustomButton`)
+    // and, while focused, consumes keyboard input for the rest of the frame.
+    // Loses focus on a click anywhere outside its own hitbox, so a second
+    // focusable element doesn't leave this input stuck consuming keystrokes.
+    // `hitbox` must have been registered via `register_hitbox` earlier this frame.
+    pub fn draw(&mut self, ctx: &mut FrameContext, hitbox: HitboxId) {
+        let pos = self.pos;
+        let dim = self.actual_dim();
+
+        let hovered = ctx.is_topmost_hovered(hitbox);
+        if ctx.let_mouse_button_released {
+            if hovered {
+                ctx.focused_input = Some(self.id);
+            } else if self.is_focused(ctx) {
+                ctx.focused_input = None;
+            }
+        }
+        let focused = self.is_focused(ctx);
+
+        if focused {
+            while let Some(c) = get_char_pressed() {
+                if self.accepts(c) {
+                    self.buffer.push(c);
+                }
+            }
+            if is_key_pressed(KeyCode::Backspace) {
+                self.buffer.pop();
+            }
+        }
+
+        let mut color = self.style.color.unwrap_or(BLACK);
+        let mut thickness = self.style.thickness.unwrap_or(2.0);
+        if focused {
+            color = ctx.theme.hover;
+            thickness = 4.0;
+        }
+        draw_rectangle_lines(pos.x, pos.y, dim.x, dim.y, thickness, color);
+
+        let mut shown = self.buffer.clone();
+        if focused && get_time().fract() < 0.5 {
+            shown.push('|');
+        }
+
+        let mut text =
+            CustomText::new(shown).font_size(self.style.font_size.unwrap_or(16));
+        text.font = self.style.font.clone();
+        text.color = self.style.color;
+        text.left_margin = Some(8.0);
+        text.pos = pos;
+        text.vertically_center(pos.y, dim.y);
+
+        text.draw();
+    }
+
+    fn actual_dim(&self) -> Vec2 {
+        self.dim.unwrap_or_else(|| vec2(300.0, 60.0))
+    }
+}
+
+impl ElementLayout for TextInput {
+    fn layout_dim(&self) -> Vec2 {
+        self.actual_dim()
+    }
+
+    fn layout_pos(&self) -> Vec2 {
+        self.pos
+    }
+
+    fn layout_set_pos(&mut self, value: Vec2) {
+        self.pos = value
+    }
 }
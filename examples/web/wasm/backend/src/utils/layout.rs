@@ -1,4 +1,6 @@
-use macroquad::math::Vec2;
+use macroquad::math::{Rect, Vec2};
+
+use crate::{FrameContext, HitboxId};
 
 pub trait ElementLayout {
     fn layout_dim(&self) -> Vec2;
@@ -7,6 +9,23 @@ pub trait ElementLayout {
     fn text_height(&self) -> Option<f32> {
         None
     }
+
+    fn layout_rect(&self) -> Rect {
+        let pos = self.layout_pos();
+        let dim = self.layout_dim();
+        Rect::new(pos.x, pos.y, dim.x, dim.y)
+    }
+
+    // Registers this element's current bounds as a hitbox, in paint order.
+    // Call once per element, for every element, before any of them is
+    // painted and in the order they will be painted, so hitbox order
+    // mirrors paint order and the topmost element wins the hover test.
+    fn register_hitbox(&self, ctx: &mut FrameContext) -> HitboxId
+    where
+        Self: Sized,
+    {
+        ctx.insert_hitbox(self.layout_rect())
+    }
     fn vertically_center(&mut self, starting_pos_y: f32, container_height: f32)
     where
         Self: Sized,
@@ -1,19 +1,25 @@
 pub mod elements;
 pub mod layout;
+pub mod theme;
 
 use macroquad::{
     input::mouse_position,
-    math::Vec2,
-    text::Font,
+    math::{Rect, Vec2},
     window::{self},
 };
 
+use crate::utils::theme::Theme;
+
 #[derive(Default)]
 pub struct FrameContext {
     pub mouse_pos: Vec2,
     pub screen_dim: Vec2,
     pub let_mouse_button_released: bool,
-    pub text_font: Option<Font>,
+    pub theme: Theme,
+    // Id of the `TextInput` focused this frame, if any. Set by `TextInput::draw`
+    // when it is clicked and read back by every input to know if it owns focus.
+    pub focused_input: Option<u64>,
+    hitboxes: Vec<Rect>,
 }
 
 impl FrameContext {
@@ -22,5 +28,30 @@ impl FrameContext {
         self.screen_dim = (window::screen_width(), window::screen_height()).into();
         self.let_mouse_button_released =
             macroquad::input::is_mouse_button_released(window::miniquad::MouseButton::Left);
+        self.hitboxes.clear();
+    }
+
+    // Registers a hitbox in paint order. Call once per element, after layout
+    // is finalized and in the same order the elements will be painted.
+    pub fn insert_hitbox(&mut self, rect: Rect) -> HitboxId {
+        self.hitboxes.push(rect);
+        HitboxId(self.hitboxes.len() - 1)
+    }
+
+    // True if the mouse sits inside this hitbox and no hitbox registered
+    // after it (i.e. painted on top of it) also contains the mouse.
+    pub fn is_topmost_hovered(&self, id: HitboxId) -> bool {
+        let Some(rect) = self.hitboxes.get(id.0) else {
+            return false;
+        };
+        if !rect.contains(self.mouse_pos) {
+            return false;
+        }
+        !self.hitboxes[id.0 + 1..]
+            .iter()
+            .any(|later| later.contains(self.mouse_pos))
     }
 }
+
+#[derive(Clone, Copy)]
+pub struct HitboxId(usize);
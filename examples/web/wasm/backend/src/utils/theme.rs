@@ -0,0 +1,105 @@
+use macroquad::{
+    color::{BLACK, Color, DARKGRAY, GREEN, RED, WHITE},
+    text::Font,
+};
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum TextRole {
+    Normal,
+    Demibold,
+    Bold,
+    Mono,
+}
+
+#[derive(Clone)]
+pub struct FontRole {
+    pub font: Option<Font>,
+    pub size: u16,
+    pub color: Color,
+}
+
+pub struct Theme {
+    pub normal: FontRole,
+    pub demibold: FontRole,
+    pub bold: FontRole,
+    pub mono: FontRole,
+    pub background: Color,
+    pub hover: Color,
+    pub disabled: Color,
+    pub danger: Color,
+}
+
+impl Theme {
+    pub fn role(&self, role: TextRole) -> &FontRole {
+        match role {
+            TextRole::Normal => &self.normal,
+            TextRole::Demibold => &self.demibold,
+            TextRole::Bold => &self.bold,
+            TextRole::Mono => &self.mono,
+        }
+    }
+
+    pub fn light() -> Self {
+        Self {
+            normal: FontRole {
+                font: None,
+                size: 32,
+                color: BLACK,
+            },
+            demibold: FontRole {
+                font: None,
+                size: 32,
+                color: BLACK,
+            },
+            bold: FontRole {
+                font: None,
+                size: 32,
+                color: BLACK,
+            },
+            mono: FontRole {
+                font: None,
+                size: 32,
+                color: BLACK,
+            },
+            background: WHITE,
+            hover: GREEN,
+            disabled: DARKGRAY,
+            danger: RED,
+        }
+    }
+
+    pub fn dark() -> Self {
+        Self {
+            normal: FontRole {
+                font: None,
+                size: 32,
+                color: WHITE,
+            },
+            demibold: FontRole {
+                font: None,
+                size: 32,
+                color: WHITE,
+            },
+            bold: FontRole {
+                font: None,
+                size: 32,
+                color: WHITE,
+            },
+            mono: FontRole {
+                font: None,
+                size: 32,
+                color: WHITE,
+            },
+            background: BLACK,
+            hover: GREEN,
+            disabled: DARKGRAY,
+            danger: RED,
+        }
+    }
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self::light()
+    }
+}